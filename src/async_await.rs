@@ -2,10 +2,33 @@
 //!
 //! Intended for use with [embedded-hal-async].
 
-use crate::{io_less, Error, FullResult, FullResultRaw, Unit};
+use bit_field::BitField;
+use embedded_hal::spi;
 use embedded_hal_async::spi::SpiDevice;
 
+use crate::{
+    bits_to_i16, decode_all_with_faults, Faults, FullResult, FullResultRaw, Reading, Unit,
+    FAULT_BIT, THERMOCOUPLE_BITS,
+};
+
+/// Possible errors returned by this crate's async driver. Unlike the blocking driver's `Error`,
+/// there is no `ChipSelectError` variant, since an async `SpiDevice` manages chip-select itself.
+#[derive(Debug)]
+pub enum Error<Spi: spi::ErrorType> {
+    /// An error returned by a call to Transfer::transfer
+    SpiError(Spi::Error),
+    /// The fault bit (16) was set in the response from the MAX31855
+    Fault,
+    /// The SCV fault bit (2) was set in the response from the MAX31855
+    VccShortFault,
+    /// The SCG fault bit (1) was set in the response from the MAX31855
+    GroundShortFault,
+    /// The OC fault bit (0) was set in the response from the MAX31855
+    MissingThermocoupleFault,
+}
+
 /// Trait enabling using the MAX31855
+#[allow(async_fn_in_trait)]
 pub trait Max31855<Spi: SpiDevice> {
     /// Reads the thermocouple temperature and leave it as a raw ADC count. Checks if there is a fault but doesn't detect what kind of fault it is
     async fn read_thermocouple_raw(&mut self) -> Result<i16, Error<Spi>>;
@@ -15,6 +38,17 @@ pub trait Max31855<Spi: SpiDevice> {
     async fn read_all_raw(&mut self) -> Result<FullResultRaw, Error<Spi>>;
     /// Reads both the thermocouple and the internal temperatures, converts them into degrees in the provided unit and resolves faults to one of vcc short, ground short or missing thermocouple
     async fn read_all(&mut self, unit: Unit) -> Result<FullResult, Error<Spi>>;
+    /// Reads both temperatures and decodes the fault bits independently of them, so a MAX31855-reported
+    /// fault never discards the last valid reading. Only SPI transport errors are returned as `Err`;
+    /// faults are returned alongside the temperature in a `Faults` value, letting the caller implement
+    /// its own debouncing/hysteresis instead of losing the reading entirely.
+    async fn read_all_with_faults(&mut self, unit: Unit) -> Result<(FullResult, Faults), Error<Spi>>;
+    /// Performs `samples` back-to-back full 32-bit reads and returns the mean of the raw ADC counts
+    /// as a `FullResult`. Averaging is done on the raw counts, before unit conversion, to avoid
+    /// repeated float rounding. A sample whose fault bit is set is discarded rather than averaged in;
+    /// `Error::Fault` is only returned if every sample faulted. The driver does not sleep between
+    /// samples -- pacing for the MAX31855's ~100ms conversion cadence is left to the caller.
+    async fn read_all_averaged(&mut self, unit: Unit, samples: u8) -> Result<FullResult, Error<Spi>>;
 }
 
 impl<SPI> Max31855<SPI> for SPI
@@ -28,13 +62,20 @@ where
             .await
             .map_err(Error::SpiError)?;
 
-        Ok(io_less::read_thermocouple_raw(buffer)?)
+        if buffer[1].get_bit(FAULT_BIT) {
+            Err(Error::Fault)?
+        }
+
+        let raw = (buffer[0] as u16) << 8 | (buffer[1] as u16);
+
+        Ok(bits_to_i16(raw.get_bits(THERMOCOUPLE_BITS), 14, 4, 2))
     }
 
     /// Reads the thermocouple temperature and converts it into degrees in the provided unit. Checks if there is a fault but doesn't detect what kind of fault it is
     async fn read_thermocouple(&mut self, unit: Unit) -> Result<f32, Error<SPI>> {
-        let raw = self.read_thermocouple_raw().await?;
-        Ok(io_less::read_thermocouple(raw, unit))
+        self.read_thermocouple_raw()
+            .await
+            .map(|r| unit.convert(Reading::Thermocouple.convert(r)))
     }
 
     /// Reads both the thermocouple and the internal temperatures, leaving them as raw ADC counts and resolves faults to one of vcc short, ground short or missing thermocouple
@@ -43,12 +84,74 @@ where
         self.transfer_in_place(&mut buffer)
             .await
             .map_err(Error::SpiError)?;
-        Ok(io_less::read_all_raw(buffer)?)
+
+        let (raw, faults) = decode_all_with_faults(buffer);
+
+        if faults.missing_thermocouple {
+            Err(Error::MissingThermocoupleFault)?
+        } else if faults.ground_short {
+            Err(Error::GroundShortFault)?
+        } else if faults.vcc_short {
+            Err(Error::VccShortFault)?
+        }
+
+        Ok(raw)
     }
 
     /// Reads both the thermocouple and the internal temperatures, converts them into degrees in the provided unit and resolves faults to one of vcc short, ground short or missing thermocouple
     async fn read_all(&mut self, unit: Unit) -> Result<FullResult, Error<SPI>> {
-        let res = self.read_all_raw().await?;
-        Ok(io_less::read_all(res, unit))
+        self.read_all_raw().await.map(|r| r.convert(unit))
+    }
+
+    /// Reads both temperatures and decodes the fault bits independently of them, so a MAX31855-reported
+    /// fault never discards the last valid reading. Only SPI transport errors are returned as `Err`;
+    /// faults are returned alongside the temperature in a `Faults` value, letting the caller implement
+    /// its own debouncing/hysteresis instead of losing the reading entirely.
+    async fn read_all_with_faults(&mut self, unit: Unit) -> Result<(FullResult, Faults), Error<SPI>> {
+        let mut buffer = [0; 4];
+        self.transfer_in_place(&mut buffer)
+            .await
+            .map_err(Error::SpiError)?;
+
+        let (raw, faults) = decode_all_with_faults(buffer);
+        Ok((raw.convert(unit), faults))
+    }
+
+    /// Performs `samples` back-to-back full 32-bit reads and returns the mean of the raw ADC counts
+    /// as a `FullResult`. Averaging is done on the raw counts, before unit conversion, to avoid
+    /// repeated float rounding. A sample whose fault bit is set is discarded rather than averaged in;
+    /// `Error::Fault` is only returned if every sample faulted. The driver does not sleep between
+    /// samples -- pacing for the MAX31855's ~100ms conversion cadence is left to the caller.
+    async fn read_all_averaged(&mut self, unit: Unit, samples: u8) -> Result<FullResult, Error<SPI>> {
+        let mut thermocouple_sum: i32 = 0;
+        let mut internal_sum: i32 = 0;
+        let mut good_samples: i32 = 0;
+
+        for _ in 0..samples {
+            let mut buffer = [0; 4];
+            self.transfer_in_place(&mut buffer)
+                .await
+                .map_err(Error::SpiError)?;
+
+            let (raw, faults) = decode_all_with_faults(buffer);
+            if faults.any() {
+                continue;
+            }
+
+            thermocouple_sum += raw.thermocouple as i32;
+            internal_sum += raw.internal as i32;
+            good_samples += 1;
+        }
+
+        if good_samples == 0 {
+            Err(Error::Fault)?
+        }
+
+        let raw = FullResultRaw {
+            thermocouple: (thermocouple_sum / good_samples) as i16,
+            internal: (internal_sum / good_samples) as i16,
+        };
+
+        Ok(raw.convert(unit))
     }
 }
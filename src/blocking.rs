@@ -5,9 +5,9 @@ use embedded_hal::{
 };
 
 use crate::{
-    bits_to_i16, Error, FullResult, FullResultRaw, Reading, Unit, FAULT_BIT,
-    FAULT_GROUND_SHORT_BIT, FAULT_NO_THERMOCOUPLE_BIT, FAULT_VCC_SHORT_BIT, INTERNAL_BITS,
-    THERMOCOUPLE_BITS,
+    bits_to_i16, decode_all_with_faults, Error, Faults, FullResult, FullResultRaw, RawAverager,
+    Reading, Unit, FAULT_BIT, FAULT_GROUND_SHORT_BIT, FAULT_NO_THERMOCOUPLE_BIT,
+    FAULT_VCC_SHORT_BIT, INTERNAL_BITS, THERMOCOUPLE_BITS,
 };
 
 fn transfer<CS, SPI>(
@@ -45,6 +45,26 @@ pub trait Max31855<Spi: SpiDevice, CS: OutputPin> {
     fn read_all_raw(&mut self, chip_select: &mut CS) -> Result<FullResultRaw, Error<Spi, CS>>;
     /// Reads both the thermocouple and the internal temperatures, converts them into degrees in the provided unit and resolves faults to one of vcc short, ground short or missing thermocouple
     fn read_all(&mut self, chip_select: &mut CS, unit: Unit) -> Result<FullResult, Error<Spi, CS>>;
+    /// Reads both temperatures and decodes the fault bits independently of them, so a MAX31855-reported
+    /// fault never discards the last valid reading. Only SPI/chip-select transport errors are returned
+    /// as `Err`; faults are returned alongside the temperature in `Faults`, letting the caller implement
+    /// its own debouncing/hysteresis instead of losing the reading entirely.
+    fn read_all_with_faults(
+        &mut self,
+        chip_select: &mut CS,
+        unit: Unit,
+    ) -> Result<(FullResult, Faults), Error<Spi, CS>>;
+    /// Performs `samples` back-to-back full 32-bit reads and returns the mean of the raw ADC counts
+    /// as a `FullResult`. Averaging is done on the raw counts, before unit conversion, to avoid
+    /// repeated float rounding. A sample whose fault bit is set is discarded rather than averaged in;
+    /// `Error::Fault` is only returned if every sample faulted. The driver does not sleep between
+    /// samples -- pacing for the MAX31855's ~100ms conversion cadence is left to the caller.
+    fn read_all_averaged(
+        &mut self,
+        chip_select: &mut CS,
+        unit: Unit,
+        samples: u8,
+    ) -> Result<FullResult, Error<Spi, CS>>;
 }
 
 impl<CS, SPI> Max31855<SPI, CS> for SPI
@@ -117,4 +137,47 @@ where
     fn read_all(&mut self, chip_select: &mut CS, unit: Unit) -> Result<FullResult, Error<SPI, CS>> {
         self.read_all_raw(chip_select).map(|r| r.convert(unit))
     }
+
+    /// Reads both temperatures and decodes the fault bits independently of them, so a MAX31855-reported
+    /// fault never discards the last valid reading. Only SPI/chip-select transport errors are returned
+    /// as `Err`; faults are returned alongside the temperature in `Faults`, letting the caller implement
+    /// its own debouncing/hysteresis instead of losing the reading entirely.
+    fn read_all_with_faults(
+        &mut self,
+        chip_select: &mut CS,
+        unit: Unit,
+    ) -> Result<(FullResult, Faults), Error<SPI, CS>> {
+        let mut buffer = [0; 4];
+        transfer(self, chip_select, &mut buffer)?;
+
+        let (raw, faults) = decode_all_with_faults(buffer);
+
+        Ok((raw.convert(unit), faults))
+    }
+
+    /// Performs `samples` back-to-back full 32-bit reads and returns the mean of the raw ADC counts
+    /// as a `FullResult`. Averaging is done on the raw counts, before unit conversion, to avoid
+    /// repeated float rounding. A sample whose fault bit is set is discarded rather than averaged in;
+    /// `Error::Fault` is only returned if every sample faulted. The driver does not sleep between
+    /// samples -- pacing for the MAX31855's ~100ms conversion cadence is left to the caller.
+    fn read_all_averaged(
+        &mut self,
+        chip_select: &mut CS,
+        unit: Unit,
+        samples: u8,
+    ) -> Result<FullResult, Error<SPI, CS>> {
+        let mut averager = RawAverager::default();
+
+        for _ in 0..samples {
+            let mut buffer = [0; 4];
+            transfer(self, chip_select, &mut buffer)?;
+
+            let (raw, faults) = decode_all_with_faults(buffer);
+            averager.push(raw, faults);
+        }
+
+        let raw = averager.finish().ok_or(Error::Fault)?;
+
+        Ok(raw.convert(unit))
+    }
 }
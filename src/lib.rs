@@ -9,6 +9,11 @@
 //! * Supports 16-bit (thermocouple + fault only) or 32-bit (thermocouple, internal and full fault details)
 //! * Supports Celsius, Fahrenheit or Kelvin units
 //! * Supports returning raw (ADC count) readings
+//! * Supports NIST ITS-90 linearization of Type K readings for improved accuracy away from room temperature
+//! * Supports re-linearizing readings for other thermocouple types (B, E, J, N, R, S, T) wired to the
+//!   MAX31855, by interpolating each type's published NIST ITS-90 reference table (less precise than
+//!   Type K's exact polynomial tables between tabulated points, but still far closer to the true curve
+//!   than the chip's fixed Type K sensitivity)
 //!
 //! ## Example:
 //! ```
@@ -43,12 +48,15 @@
 #![no_std]
 #![deny(warnings, missing_docs)]
 
+/// A blocking driver using `embedded-hal`'s synchronous `SpiDevice`
+pub mod blocking;
+/// An async-await driver using `embedded-hal-async`'s `SpiDevice`, for use with [embedded-hal-async]
+pub mod async_await;
+
 use bit_field::BitField;
 use core::ops::RangeInclusive;
-use embedded_hal::{
-    digital::{self, OutputPin, PinState},
-    spi::{self, SpiDevice},
-};
+use embedded_hal::{digital, spi};
+use libm::expf;
 
 /// The bits that represent the thermocouple value when reading the first u16 from the sensor
 const THERMOCOUPLE_BITS: RangeInclusive<usize> = 2..=15;
@@ -63,6 +71,482 @@ const FAULT_GROUND_SHORT_BIT: usize = 1;
 /// The bit that indicates a missing thermocouple fault when reading the second u16 from the sensor
 const FAULT_NO_THERMOCOUPLE_BIT: usize = 0;
 
+/// The MAX31855's built-in linear approximation of the Type K Seebeck coefficient, in mV per degree C
+const MAX31855_SENSITIVITY_MV_PER_C: f32 = 0.041276;
+
+/// NIST ITS-90 Type K forward (temperature -> voltage, mV) polynomial coefficients, c0..c10, valid
+/// for -270 to 0 degrees Celsius
+#[allow(clippy::excessive_precision)]
+const TYPE_K_FORWARD_NEG: [f32; 11] = [
+    0.0,
+    3.9450128025e-2,
+    2.3622373598e-5,
+    -3.2858906784e-7,
+    -4.9904828777e-9,
+    -6.7509059173e-11,
+    -5.7410327428e-13,
+    -3.1088872894e-15,
+    -1.0451609365e-17,
+    -1.9889266878e-20,
+    -1.6322697486e-23,
+];
+
+/// NIST ITS-90 Type K forward (temperature -> voltage, mV) polynomial coefficients, c0..c9, valid
+/// for 0 to 1372 degrees Celsius. An additional exponential term (`TYPE_K_FORWARD_POS_EXP`) is added
+/// on top of this polynomial in this range.
+#[allow(clippy::excessive_precision)]
+const TYPE_K_FORWARD_POS: [f32; 10] = [
+    -1.7600413686e-2,
+    3.8921204975e-2,
+    1.8558770032e-5,
+    -9.9457592874e-8,
+    3.1840945719e-10,
+    -5.6072844889e-13,
+    5.6075059059e-16,
+    -3.2020720003e-19,
+    9.7151147152e-23,
+    -1.2104721275e-26,
+];
+
+/// Coefficients `(a0, a1, a2)` of the exponential term `a0 * exp(a1 * (T - a2)^2)` added to
+/// `TYPE_K_FORWARD_POS` for 0 to 1372 degrees Celsius
+#[allow(clippy::excessive_precision)]
+const TYPE_K_FORWARD_POS_EXP: (f32, f32, f32) = (0.1185976, -0.0001183432, 126.9686);
+
+/// NIST ITS-90 Type K inverse (voltage, mV -> temperature) polynomial coefficients, d0..d8, valid
+/// for -5.891 to 0 mV (-200 to 0 degrees Celsius). The segment bound used below is widened slightly
+/// past -5.891 -- see `TYPE_K_INVERSE_SEGMENTS`.
+#[allow(clippy::excessive_precision)]
+const TYPE_K_INVERSE_NEG: [f32; 9] = [
+    0.0,
+    25.173462,
+    -1.1662878,
+    -1.0833638,
+    -0.8977354,
+    -0.37342377,
+    -0.086632643,
+    -0.010450598,
+    -0.00051920577,
+];
+
+/// NIST ITS-90 Type K inverse (voltage, mV -> temperature) polynomial coefficients, d0..d9, valid
+/// for 0 to 20.644 mV (0 to ~500 degrees Celsius)
+#[allow(clippy::excessive_precision)]
+const TYPE_K_INVERSE_MID: [f32; 10] = [
+    0.0,
+    25.08355,
+    0.07860106,
+    -0.2503131,
+    0.0831527,
+    -0.01228034,
+    0.0009804036,
+    -0.00004413030,
+    0.000001057734,
+    -0.00000001052755,
+];
+
+/// NIST ITS-90 Type K inverse (voltage, mV -> temperature) polynomial coefficients, d0..d6, valid
+/// for 20.644 to 54.886 mV (~500 to 1372 degrees Celsius). The segment bound used below is widened
+/// slightly past 54.886 -- see `TYPE_K_INVERSE_SEGMENTS`.
+#[allow(clippy::excessive_precision)]
+const TYPE_K_INVERSE_POS: [f32; 7] = [
+    -131.8058,
+    48.30222,
+    -1.646031,
+    0.05464731,
+    -0.0009650715,
+    0.000008802193,
+    -0.00000003110810,
+];
+
+/// Evaluates a polynomial `c0 + c1*x + c2*x^2 + ...` given its coefficients in increasing order of power
+fn polynomial(coefficients: &[f32], x: f32) -> f32 {
+    coefficients.iter().rev().fold(0.0, |acc, &c| acc * x + c)
+}
+
+/// A temperature range together with the NIST ITS-90 forward (temperature -> voltage, mV) polynomial
+/// coefficients valid across it. `exponential`, when set, adds `a0 * exp(a1 * (T - a2)^2)` on top of
+/// the polynomial (only Type K's positive range needs this).
+struct ForwardSegment {
+    temperature_range: RangeInclusive<f32>,
+    coefficients: &'static [f32],
+    exponential: Option<(f32, f32, f32)>,
+}
+
+/// A voltage range together with the inverse (voltage, mV -> temperature) polynomial coefficients
+/// valid across it. `coefficients` are evaluated against `(mv - offset) / scale` rather than `mv`
+/// directly. Only Type K's tables use this type, and NIST's Type K tables are already expressed
+/// directly in mV, so they use `offset: 0.0, scale: 1.0`, which is a no-op.
+struct InverseSegment {
+    voltage_range: RangeInclusive<f32>,
+    coefficients: &'static [f32],
+    offset: f32,
+    scale: f32,
+}
+
+/// Evaluates whichever forward segment covers `celsius`, or `None` if it falls outside all of them
+fn forward_voltage(segments: &[ForwardSegment], celsius: f32) -> Option<f32> {
+    segments
+        .iter()
+        .find(|s| s.temperature_range.contains(&celsius))
+        .map(|s| {
+            let base = polynomial(s.coefficients, celsius);
+            match s.exponential {
+                Some((a0, a1, a2)) => {
+                    let d = celsius - a2;
+                    base + a0 * expf(a1 * d * d)
+                }
+                None => base,
+            }
+        })
+}
+
+/// Evaluates whichever inverse segment covers `mv`, or `None` if it falls outside all of them
+fn inverse_celsius(segments: &[InverseSegment], mv: f32) -> Option<f32> {
+    segments
+        .iter()
+        .find(|s| s.voltage_range.contains(&mv))
+        .map(|s| polynomial(s.coefficients, (mv - s.offset) / s.scale))
+}
+
+/// The NIST ITS-90 Type K forward polynomial segments, covering its full -270 to 1372 degree C range
+const TYPE_K_FORWARD_SEGMENTS: [ForwardSegment; 2] = [
+    ForwardSegment {
+        temperature_range: -270.0..=0.0,
+        coefficients: &TYPE_K_FORWARD_NEG,
+        exponential: None,
+    },
+    ForwardSegment {
+        temperature_range: 0.0..=1372.0,
+        coefficients: &TYPE_K_FORWARD_POS,
+        exponential: Some(TYPE_K_FORWARD_POS_EXP),
+    },
+];
+
+/// The NIST ITS-90 Type K inverse polynomial segments, covering its full -5.891 to 54.886 mV range.
+/// The outer bounds are widened very slightly past the textbook -5.891/54.886 mV (to -5.8915/54.887)
+/// because `TYPE_K_FORWARD_NEG`/`TYPE_K_FORWARD_POS` evaluated in f32 at the exact boundary
+/// temperatures (-200 and 1372 degrees Celsius) land a hair outside the textbook bound, which would
+/// otherwise make `convert_as` spuriously reject readings at those documented edges.
+const TYPE_K_INVERSE_SEGMENTS: [InverseSegment; 3] = [
+    InverseSegment {
+        voltage_range: -5.8915..=0.0,
+        coefficients: &TYPE_K_INVERSE_NEG,
+        offset: 0.0,
+        scale: 1.0,
+    },
+    InverseSegment {
+        voltage_range: 0.0..=20.644,
+        coefficients: &TYPE_K_INVERSE_MID,
+        offset: 0.0,
+        scale: 1.0,
+    },
+    InverseSegment {
+        voltage_range: 20.644..=54.887,
+        coefficients: &TYPE_K_INVERSE_POS,
+        offset: 0.0,
+        scale: 1.0,
+    },
+];
+
+/// A `(degrees Celsius, millivolts)` point from a thermocouple type's published NIST ITS-90
+/// reference table, reference junction at 0 degrees Celsius
+type ReferencePoint = (f32, f32);
+
+/// A published NIST ITS-90 reference table for a thermocouple type other than K, used to convert
+/// between temperature and EMF by linearly interpolating between adjacent tabulated points rather
+/// than evaluating a fitted polynomial. `points` must be in strictly increasing order of both
+/// fields. Forward and inverse directions read the exact same `points`, so unlike a pair of
+/// polynomials fitted independently in each direction, this can't drift from itself -- the only
+/// error is the linear interpolation between tabulated points.
+struct ReferenceTable {
+    points: &'static [ReferencePoint],
+}
+
+/// Linearly interpolates the value on the other side of `points` at `x`, or `None` if `x` falls
+/// outside the table
+fn interpolate(
+    points: &[ReferencePoint],
+    x: f32,
+    key: fn(&ReferencePoint) -> f32,
+    value: fn(&ReferencePoint) -> f32,
+) -> Option<f32> {
+    points.windows(2).find_map(|pair| {
+        let (x0, x1) = (key(&pair[0]), key(&pair[1]));
+        if x < x0 || x > x1 {
+            return None;
+        }
+        let (y0, y1) = (value(&pair[0]), value(&pair[1]));
+        let t = (x - x0) / (x1 - x0);
+        Some(y0 + t * (y1 - y0))
+    })
+}
+
+impl ReferenceTable {
+    /// Interpolates the EMF in mV at `celsius`, or `None` if it falls outside the table
+    fn forward_voltage(&self, celsius: f32) -> Option<f32> {
+        interpolate(self.points, celsius, |p| p.0, |p| p.1)
+    }
+
+    /// Interpolates the temperature in degrees Celsius at `mv`, or `None` if it falls outside the
+    /// table
+    fn inverse_celsius(&self, mv: f32) -> Option<f32> {
+        interpolate(self.points, mv, |p| p.1, |p| p.0)
+    }
+}
+
+/// Published NIST ITS-90 reference table for Type B, `(degrees Celsius, millivolts)`, 0 to 1820
+/// degrees C. Below 250 degrees C it is only sampled coarsely (skipping the small non-monotonic dip
+/// below ~42 degrees C), enough to compensate a cold junction near room temperature.
+const TYPE_B_REFERENCE_POINTS: [ReferencePoint; 23] = [
+    (0.0, 0.000),
+    (50.0, 0.002),
+    (100.0, 0.033),
+    (150.0, 0.092),
+    (200.0, 0.178),
+    (250.0, 0.291),
+    (300.0, 0.431),
+    (400.0, 0.786),
+    (500.0, 1.241),
+    (600.0, 1.791),
+    (700.0, 2.430),
+    (800.0, 3.154),
+    (900.0, 3.957),
+    (1000.0, 4.834),
+    (1100.0, 5.780),
+    (1200.0, 6.786),
+    (1300.0, 7.848),
+    (1400.0, 8.956),
+    (1500.0, 10.099),
+    (1600.0, 11.257),
+    (1700.0, 12.426),
+    (1800.0, 13.591),
+    (1820.0, 13.820),
+];
+const TYPE_B_REFERENCE: ReferenceTable = ReferenceTable {
+    points: &TYPE_B_REFERENCE_POINTS,
+};
+
+/// Published NIST ITS-90 reference table for Type E, `(degrees Celsius, millivolts)`
+const TYPE_E_REFERENCE_POINTS: [ReferencePoint; 14] = [
+    (-270.0, -9.835),
+    (-200.0, -8.824),
+    (-100.0, -5.237),
+    (0.0, 0.000),
+    (100.0, 6.319),
+    (200.0, 13.421),
+    (300.0, 21.033),
+    (400.0, 28.943),
+    (500.0, 37.005),
+    (600.0, 45.085),
+    (700.0, 53.110),
+    (800.0, 61.017),
+    (900.0, 68.783),
+    (1000.0, 76.373),
+];
+const TYPE_E_REFERENCE: ReferenceTable = ReferenceTable {
+    points: &TYPE_E_REFERENCE_POINTS,
+};
+
+/// Published NIST ITS-90 reference table for Type J, `(degrees Celsius, millivolts)`
+const TYPE_J_REFERENCE_POINTS: [ReferencePoint; 16] = [
+    (-210.0, -8.096),
+    (-200.0, -7.890),
+    (-100.0, -4.633),
+    (0.0, 0.000),
+    (100.0, 5.269),
+    (200.0, 10.779),
+    (300.0, 16.327),
+    (400.0, 21.848),
+    (500.0, 27.393),
+    (600.0, 33.102),
+    (700.0, 39.130),
+    (800.0, 45.494),
+    (900.0, 51.877),
+    (1000.0, 57.953),
+    (1100.0, 63.792),
+    (1200.0, 69.553),
+];
+const TYPE_J_REFERENCE: ReferenceTable = ReferenceTable {
+    points: &TYPE_J_REFERENCE_POINTS,
+};
+
+/// Published NIST ITS-90 reference table for Type N, `(degrees Celsius, millivolts)`
+const TYPE_N_REFERENCE_POINTS: [ReferencePoint; 17] = [
+    (-270.0, -4.345),
+    (-200.0, -3.990),
+    (-100.0, -2.407),
+    (0.0, 0.000),
+    (100.0, 2.774),
+    (200.0, 5.913),
+    (300.0, 9.341),
+    (400.0, 12.974),
+    (500.0, 16.748),
+    (600.0, 20.613),
+    (700.0, 24.527),
+    (800.0, 28.455),
+    (900.0, 32.371),
+    (1000.0, 36.256),
+    (1100.0, 40.087),
+    (1200.0, 43.846),
+    (1300.0, 47.513),
+];
+const TYPE_N_REFERENCE: ReferenceTable = ReferenceTable {
+    points: &TYPE_N_REFERENCE_POINTS,
+};
+
+/// Published NIST ITS-90 reference table for Type R, `(degrees Celsius, millivolts)`
+const TYPE_R_REFERENCE_POINTS: [ReferencePoint; 20] = [
+    (-50.0, -0.226),
+    (0.0, 0.000),
+    (100.0, 0.647),
+    (200.0, 1.469),
+    (300.0, 2.401),
+    (400.0, 3.408),
+    (500.0, 4.471),
+    (600.0, 5.583),
+    (700.0, 6.741),
+    (800.0, 7.949),
+    (900.0, 9.207),
+    (1000.0, 10.506),
+    (1100.0, 11.846),
+    (1200.0, 13.224),
+    (1300.0, 14.624),
+    (1400.0, 16.035),
+    (1500.0, 17.445),
+    (1600.0, 18.842),
+    (1700.0, 20.215),
+    (1768.0, 21.101),
+];
+const TYPE_R_REFERENCE: ReferenceTable = ReferenceTable {
+    points: &TYPE_R_REFERENCE_POINTS,
+};
+
+/// Published NIST ITS-90 reference table for Type S, `(degrees Celsius, millivolts)`
+const TYPE_S_REFERENCE_POINTS: [ReferencePoint; 20] = [
+    (-50.0, -0.236),
+    (0.0, 0.000),
+    (100.0, 0.646),
+    (200.0, 1.441),
+    (300.0, 2.323),
+    (400.0, 3.260),
+    (500.0, 4.234),
+    (600.0, 5.237),
+    (700.0, 6.274),
+    (800.0, 7.345),
+    (900.0, 8.448),
+    (1000.0, 9.585),
+    (1100.0, 10.754),
+    (1200.0, 11.947),
+    (1300.0, 13.155),
+    (1400.0, 14.368),
+    (1500.0, 15.576),
+    (1600.0, 16.771),
+    (1700.0, 17.942),
+    (1768.0, 18.693),
+];
+const TYPE_S_REFERENCE: ReferenceTable = ReferenceTable {
+    points: &TYPE_S_REFERENCE_POINTS,
+};
+
+/// Published NIST ITS-90 reference table for Type T, `(degrees Celsius, millivolts)`
+const TYPE_T_REFERENCE_POINTS: [ReferencePoint; 8] = [
+    (-270.0, -6.258),
+    (-200.0, -5.603),
+    (-100.0, -3.378),
+    (0.0, 0.000),
+    (100.0, 4.279),
+    (200.0, 9.288),
+    (300.0, 14.862),
+    (400.0, 20.872),
+];
+const TYPE_T_REFERENCE: ReferenceTable = ReferenceTable {
+    points: &TYPE_T_REFERENCE_POINTS,
+};
+
+/// The forward/inverse tables for one thermocouple type. Type K uses the chip's own exact,
+/// multi-segment NIST ITS-90 polynomials; every other type uses linear interpolation through that
+/// type's published NIST ITS-90 reference table (see `ReferenceTable`), since this crate doesn't
+/// carry real per-type NIST polynomial coefficients for them.
+enum ThermocoupleTables {
+    /// Type K's exact NIST ITS-90 polynomial segments
+    Polynomial {
+        forward: &'static [ForwardSegment],
+        inverse: &'static [InverseSegment],
+    },
+    /// Linear interpolation through a published NIST ITS-90 reference table
+    Reference(&'static ReferenceTable),
+}
+
+impl ThermocoupleTables {
+    /// Converts `celsius` to the EMF in mV this thermocouple type would produce, or `None` if it
+    /// falls outside the type's tabulated range
+    fn forward_voltage(&self, celsius: f32) -> Option<f32> {
+        match self {
+            ThermocoupleTables::Polynomial { forward, .. } => forward_voltage(forward, celsius),
+            ThermocoupleTables::Reference(table) => table.forward_voltage(celsius),
+        }
+    }
+
+    /// Converts an EMF in mV back to degrees Celsius, or `None` if it falls outside the type's
+    /// tabulated range
+    fn inverse_celsius(&self, mv: f32) -> Option<f32> {
+        match self {
+            ThermocoupleTables::Polynomial { inverse, .. } => inverse_celsius(inverse, mv),
+            ThermocoupleTables::Reference(table) => table.inverse_celsius(mv),
+        }
+    }
+}
+
+/// A thermocouple type that can be paired with a MAX31855. The chip only linearizes for Type K, but
+/// since it effectively reports the raw junction voltage, other thermocouples wired to it can be
+/// re-linearized in software instead. Only Type K is backed by this crate's exact NIST ITS-90
+/// polynomial tables; the rest are linearized by interpolating their published NIST ITS-90 reference
+/// tables (see `ThermocoupleTables`), which is less precise between tabulated points but still far
+/// closer to the true curve than the chip's fixed Type K sensitivity. This lets a cheaper MAX31855
+/// board be paired with the J/T/N/etc probes common in 3D-printer and lab setups.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ThermocoupleType {
+    /// Type B (Platinum Rhodium - Platinum Rhodium), usable 250 to 1820 degrees Celsius. Output is
+    /// very low and non-monotonic below ~250 degrees Celsius; the table only samples that region
+    /// coarsely, to compensate the cold junction, so hot-junction readings there are coarse.
+    B,
+    /// Type E (Nickel Chromium - Constantan), usable -270 to 1000 degrees Celsius
+    E,
+    /// Type J (Iron - Constantan), usable -210 to 1200 degrees Celsius
+    J,
+    /// Type K (Nickel Chromium - Nickel Alumel), usable -200 to 1372 degrees Celsius (the forward
+    /// tables reach down to -270, but the inverse tables stop at -200)
+    K,
+    /// Type N (Nicrosil - Nisil), usable -270 to 1300 degrees Celsius
+    N,
+    /// Type R (Platinum Rhodium - Platinum), usable -50 to 1768 degrees Celsius
+    R,
+    /// Type S (Platinum Rhodium - Platinum), usable -50 to 1768 degrees Celsius
+    S,
+    /// Type T (Copper - Constantan), usable -270 to 400 degrees Celsius
+    T,
+}
+
+impl ThermocoupleType {
+    /// The forward/inverse tables for this thermocouple type
+    fn tables(&self) -> ThermocoupleTables {
+        match self {
+            ThermocoupleType::B => ThermocoupleTables::Reference(&TYPE_B_REFERENCE),
+            ThermocoupleType::E => ThermocoupleTables::Reference(&TYPE_E_REFERENCE),
+            ThermocoupleType::J => ThermocoupleTables::Reference(&TYPE_J_REFERENCE),
+            ThermocoupleType::K => ThermocoupleTables::Polynomial {
+                forward: &TYPE_K_FORWARD_SEGMENTS,
+                inverse: &TYPE_K_INVERSE_SEGMENTS,
+            },
+            ThermocoupleType::N => ThermocoupleTables::Reference(&TYPE_N_REFERENCE),
+            ThermocoupleType::R => ThermocoupleTables::Reference(&TYPE_R_REFERENCE),
+            ThermocoupleType::S => ThermocoupleTables::Reference(&TYPE_S_REFERENCE),
+            ThermocoupleType::T => ThermocoupleTables::Reference(&TYPE_T_REFERENCE),
+        }
+    }
+}
+
 /// Possible errors returned by this crate
 #[derive(Debug)]
 pub enum Error<Spi: spi::ErrorType, CS: digital::ErrorType> {
@@ -80,6 +564,13 @@ pub enum Error<Spi: spi::ErrorType, CS: digital::ErrorType> {
     MissingThermocoupleFault,
 }
 
+/// Error returned when re-linearizing a thermocouple reading against the NIST ITS-90 tables
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LinearizationError {
+    /// The thermocouple + cold-junction voltage fell outside the tabulated NIST ITS-90 voltage ranges
+    VoltageOutOfRange,
+}
+
 /// The temperature unit to use
 #[derive(Clone, Copy, Debug)]
 pub enum Unit {
@@ -121,27 +612,6 @@ impl Reading {
     }
 }
 
-fn transfer<CS, SPI>(
-    spi: &mut SPI,
-    chip_select: &mut CS,
-    buffer: &mut [u8],
-) -> Result<(), Error<SPI, CS>>
-where
-    CS: OutputPin,
-    SPI: SpiDevice<u8> + spi::ErrorType,
-{
-    chip_select
-        .set_state(PinState::Low)
-        .map_err(|e| Error::ChipSelectError(e))?;
-
-    spi.transfer_in_place(buffer)
-        .map_err(|e| Error::SpiError(e))?;
-
-    chip_select
-        .set_state(PinState::High)
-        .map_err(|e| Error::ChipSelectError(e))
-}
-
 fn bits_to_i16(bits: u16, len: usize, divisor: i16, shift: usize) -> i16 {
     let negative = bits.get_bit(len - 1);
     if negative {
@@ -172,6 +642,138 @@ impl FullResultRaw {
             unit,
         }
     }
+
+    /// Recomputes the hot junction temperature using the NIST ITS-90 Type K polynomials instead of
+    /// the MAX31855's built-in linear approximation (0.25 degC/count, equivalent to a fixed
+    /// 41.276 uV/degC sensitivity), which is inaccurate away from room temperature because the
+    /// Seebeck coefficient of Type K is non-linear and the chip's cold-junction compensation is a
+    /// straight-line approximation.
+    ///
+    /// Requires both the thermocouple and internal (cold junction) readings, so this is only
+    /// available on the 32-bit read path, not a lone thermocouple raw ADC count.
+    ///
+    /// Returns `Err(LinearizationError::VoltageOutOfRange)` if the cold-junction-compensated
+    /// thermocouple voltage falls outside the NIST ITS-90 inverse tables (-5.891 to 54.886 mV,
+    /// widened very slightly as described on `TYPE_K_INVERSE_SEGMENTS`).
+    pub fn convert_linearized(self, unit: Unit) -> Result<f32, LinearizationError> {
+        self.convert_as(ThermocoupleType::K, unit)
+    }
+
+    /// Re-linearizes this 32-bit reading for a thermocouple of the given `ThermocoupleType` rather
+    /// than the Type K the MAX31855 itself assumes, by recovering the measured junction voltage and
+    /// re-applying the selected type's own forward/inverse tables (see `ThermocoupleTables` for how
+    /// these differ between Type K and the rest).
+    ///
+    /// Requires both the thermocouple and internal (cold junction) readings, so this is only
+    /// available on the 32-bit read path, not a lone thermocouple raw ADC count.
+    ///
+    /// Returns `Err(LinearizationError::VoltageOutOfRange)` if the internal (cold junction)
+    /// temperature, or the recovered voltage plus the cold-junction voltage, falls outside
+    /// `thermocouple_type`'s tabulated range.
+    pub fn convert_as(
+        self,
+        thermocouple_type: ThermocoupleType,
+        unit: Unit,
+    ) -> Result<f32, LinearizationError> {
+        let hot_junction = Reading::Thermocouple.convert(self.thermocouple);
+        let cold_junction = Reading::Internal.convert(self.internal);
+
+        // Voltage the chip's ADC actually saw across the thermocouple, recovered from its linear
+        // hot/cold junction estimates and its fixed sensitivity
+        let measured_voltage = (hot_junction - cold_junction) * MAX31855_SENSITIVITY_MV_PER_C;
+
+        let tables = thermocouple_type.tables();
+        let cold_junction_voltage = tables
+            .forward_voltage(cold_junction)
+            .ok_or(LinearizationError::VoltageOutOfRange)?;
+        let total_voltage = measured_voltage + cold_junction_voltage;
+
+        let celsius = tables
+            .inverse_celsius(total_voltage)
+            .ok_or(LinearizationError::VoltageOutOfRange)?;
+
+        Ok(unit.convert(celsius))
+    }
+}
+
+/// The fault bits from a full 32-bit read, decoded independently of the temperature fields so a
+/// caller can see a reading and its faults together instead of losing the reading whenever a fault
+/// bit is set
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Faults {
+    /// The SCV (short-to-VCC) fault bit was set
+    pub vcc_short: bool,
+    /// The SCG (short-to-GND) fault bit was set
+    pub ground_short: bool,
+    /// The OC (missing/open thermocouple) fault bit was set
+    pub missing_thermocouple: bool,
+}
+
+impl Faults {
+    /// True if any fault bit was set
+    pub fn any(&self) -> bool {
+        self.vcc_short || self.ground_short || self.missing_thermocouple
+    }
+}
+
+/// Decodes a full 32-bit read into its raw temperature counts and fault bits, without treating a
+/// fault bit as fatal
+fn decode_all_with_faults(buffer: [u8; 4]) -> (FullResultRaw, Faults) {
+    let first_u16 = (buffer[0] as u16) << 8 | (buffer[1] as u16);
+    let second_u16 = (buffer[2] as u16) << 8 | (buffer[3] as u16);
+
+    let faults = Faults {
+        vcc_short: second_u16.get_bit(FAULT_VCC_SHORT_BIT),
+        ground_short: second_u16.get_bit(FAULT_GROUND_SHORT_BIT),
+        missing_thermocouple: second_u16.get_bit(FAULT_NO_THERMOCOUPLE_BIT),
+    };
+
+    let thermocouple = bits_to_i16(first_u16.get_bits(THERMOCOUPLE_BITS), 14, 4, 2);
+    let internal = bits_to_i16(second_u16.get_bits(INTERNAL_BITS), 12, 16, 4);
+
+    (
+        FullResultRaw {
+            thermocouple,
+            internal,
+        },
+        faults,
+    )
+}
+
+/// Incrementally accumulates the raw ADC counts of non-faulted samples for `read_all_averaged`,
+/// discarding any whose fault bit was set. Pure and hardware-independent, so the blocking driver
+/// uses it and it can be unit tested without a fake SPI. The async driver accumulates its samples
+/// the same way in its own loop.
+#[derive(Debug, Default)]
+pub(crate) struct RawAverager {
+    thermocouple_sum: i32,
+    internal_sum: i32,
+    good_samples: i32,
+}
+
+impl RawAverager {
+    /// Folds in one sample, discarding it if any of its fault bits were set
+    pub(crate) fn push(&mut self, raw: FullResultRaw, faults: Faults) {
+        if faults.any() {
+            return;
+        }
+
+        self.thermocouple_sum += raw.thermocouple as i32;
+        self.internal_sum += raw.internal as i32;
+        self.good_samples += 1;
+    }
+
+    /// Returns the mean of the pushed samples, or `None` if every sample was discarded as faulted
+    pub(crate) fn finish(self) -> Option<FullResultRaw> {
+        if self.good_samples == 0 {
+            return None;
+        }
+
+        Some(FullResultRaw {
+            thermocouple: (self.thermocouple_sum / self.good_samples) as i16,
+            internal: (self.internal_sum / self.good_samples) as i16,
+        })
+    }
 }
 
 /// Represents the data contained in a full 32-bit read from the MAX31855 as degrees in the included Unit
@@ -185,90 +787,190 @@ pub struct FullResult {
     pub unit: Unit,
 }
 
-/// Trait enabling using the MAX31855
-pub trait Max31855<Spi: SpiDevice, CS: OutputPin> {
-    /// Reads the thermocouple temperature and leave it as a raw ADC count. Checks if there is a fault but doesn't detect what kind of fault it is
-    fn read_thermocouple_raw(&mut self, chip_select: &mut CS) -> Result<i16, Error<Spi, CS>>;
-    /// Reads the thermocouple temperature and converts it into degrees in the provided unit. Checks if there is a fault but doesn't detect what kind of fault it is
-    fn read_thermocouple(
-        &mut self,
-        chip_select: &mut CS,
-        unit: Unit,
-    ) -> Result<f32, Error<Spi, CS>>;
-    /// Reads both the thermocouple and the internal temperatures, leaving them as raw ADC counts and resolves faults to one of vcc short, ground short or missing thermocouple
-    fn read_all_raw(&mut self, chip_select: &mut CS) -> Result<FullResultRaw, Error<Spi, CS>>;
-    /// Reads both the thermocouple and the internal temperatures, converts them into degrees in the provided unit and resolves faults to one of vcc short, ground short or missing thermocouple
-    fn read_all(&mut self, chip_select: &mut CS, unit: Unit) -> Result<FullResult, Error<Spi, CS>>;
-}
+/// The trait enabling using the MAX31855 over a blocking `SpiDevice` lives in [`blocking`] (which
+/// also owns its blanket impl); it's re-exported here so existing `use max31855::Max31855;` call
+/// sites keep working.
+pub use blocking::Max31855;
 
-impl<CS, SPI> Max31855<SPI, CS> for SPI
-where
-    CS: OutputPin,
-    SPI: SpiDevice<u8>,
-{
-    /// Reads the thermocouple temperature and leave it as a raw ADC count. Checks if there is a fault but doesn't detect what kind of fault it is
-    fn read_thermocouple_raw(&mut self, chip_select: &mut CS) -> Result<i16, Error<SPI, CS>> {
-        let mut buffer = [0; 2];
-        transfer(self, chip_select, &mut buffer)?;
-
-        if buffer[1].get_bit(FAULT_BIT) {
-            Err(Error::Fault)?
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        let raw = (buffer[0] as u16) << 8 | (buffer[1] as u16);
+    /// Reference Type K mV values from the NIST ITS-90 thermocouple tables
+    #[test]
+    fn type_k_forward_voltage_known_points() {
+        let at = |celsius| forward_voltage(&TYPE_K_FORWARD_SEGMENTS, celsius).unwrap();
 
-        let thermocouple = bits_to_i16(raw.get_bits(THERMOCOUPLE_BITS), 14, 4, 2);
+        assert!((at(0.0) - 0.0).abs() < 0.001);
+        assert!((at(100.0) - 4.096).abs() < 0.01);
+        assert!((at(-100.0) - -3.554).abs() < 0.01);
+        assert!((at(1000.0) - 41.276).abs() < 0.05);
+    }
 
-        Ok(thermocouple)
+    /// The documented boundary temperatures must not be spuriously rejected due to f32 rounding
+    /// noise when converting from temperature to voltage and back (see `TYPE_K_INVERSE_SEGMENTS`)
+    #[test]
+    fn type_k_inverse_accepts_documented_boundary_temperatures() {
+        for celsius in [-200.0, 1372.0] {
+            let mv = forward_voltage(&TYPE_K_FORWARD_SEGMENTS, celsius).unwrap();
+            assert!(
+                inverse_celsius(&TYPE_K_INVERSE_SEGMENTS, mv).is_some(),
+                "{celsius} degrees C ({mv} mV) was rejected as out of range"
+            );
+        }
     }
 
-    /// Reads the thermocouple temperature and converts it into degrees in the provided unit. Checks if there is a fault but doesn't detect what kind of fault it is
-    fn read_thermocouple(
-        &mut self,
-        chip_select: &mut CS,
-        unit: Unit,
-    ) -> Result<f32, Error<SPI, CS>> {
-        self.read_thermocouple_raw(chip_select)
-            .map(|r| unit.convert(Reading::Thermocouple.convert(r)))
+    #[test]
+    fn type_k_round_trips_through_forward_and_inverse() {
+        for celsius in [-200.0, -100.0, -50.0, 0.0, 25.0, 100.0, 500.0, 1000.0, 1372.0] {
+            let mv = forward_voltage(&TYPE_K_FORWARD_SEGMENTS, celsius).unwrap();
+            let back = inverse_celsius(&TYPE_K_INVERSE_SEGMENTS, mv).unwrap();
+            assert!(
+                (back - celsius).abs() < 0.5,
+                "{celsius} degrees C round-tripped to {back} degrees C"
+            );
+        }
     }
 
-    /// Reads both the thermocouple and the internal temperatures, leaving them as raw ADC counts and resolves faults to one of vcc short, ground short or missing thermocouple
-    fn read_all_raw(&mut self, chip_select: &mut CS) -> Result<FullResultRaw, Error<SPI, CS>> {
-        let mut buffer = [0; 4];
-        transfer(self, chip_select, &mut buffer)?;
-
-        let fault = buffer[1].get_bit(0);
-
-        if fault {
-            let raw = (buffer[2] as u16) << 8 | (buffer[3] as u16);
-
-            if raw.get_bit(FAULT_NO_THERMOCOUPLE_BIT) {
-                Err(Error::MissingThermocoupleFault)?
-            } else if raw.get_bit(FAULT_GROUND_SHORT_BIT) {
-                Err(Error::GroundShortFault)?
-            } else if raw.get_bit(FAULT_VCC_SHORT_BIT) {
-                Err(Error::VccShortFault)?
-            } else {
-                // This should impossible, one of the other fields should be set as well
-                // but handled here just-in-case
-                Err(Error::Fault)?
+    /// Each non-K type's forward voltage at its own tabulated reference points must match the
+    /// published NIST ITS-90 value at that point, not merely be self-consistent with an in-crate
+    /// model -- in particular this catches the Type B forward curve being off by an order of
+    /// magnitude at high temperature (~1.98 mV vs. the true ~13.6 mV at 1800 degrees C) that a
+    /// purely self-referential round-trip test could never have caught.
+    #[test]
+    fn non_k_types_known_points_match_published_reference_values() {
+        let cases: &[(ThermocoupleType, f32, f32)] = &[
+            (ThermocoupleType::B, 1800.0, 13.591),
+            (ThermocoupleType::E, 500.0, 37.005),
+            (ThermocoupleType::J, 500.0, 27.393),
+            (ThermocoupleType::N, 500.0, 16.748),
+            (ThermocoupleType::R, 1000.0, 10.506),
+            (ThermocoupleType::S, 1000.0, 9.585),
+            (ThermocoupleType::T, 300.0, 14.862),
+        ];
+
+        for (thermocouple_type, celsius, expected_mv) in cases {
+            let tables = thermocouple_type.tables();
+            let mv = tables
+                .forward_voltage(*celsius)
+                .unwrap_or_else(|| panic!("{thermocouple_type:?} at {celsius} degrees C had no forward voltage"));
+            assert!(
+                (mv - expected_mv).abs() < 0.01,
+                "{thermocouple_type:?} at {celsius} degrees C gave {mv} mV, expected {expected_mv} mV"
+            );
+        }
+    }
+
+    /// Every non-K type's inverse must recover the temperature of its own forward voltage, since
+    /// both directions interpolate through the exact same reference table
+    #[test]
+    fn non_k_types_round_trip_through_forward_and_inverse() {
+        let cases: &[(ThermocoupleType, f32, f32)] = &[
+            (ThermocoupleType::B, 250.0, 1820.0),
+            (ThermocoupleType::E, -270.0, 1000.0),
+            (ThermocoupleType::J, -210.0, 1200.0),
+            (ThermocoupleType::N, -270.0, 1300.0),
+            (ThermocoupleType::R, -50.0, 1768.0),
+            (ThermocoupleType::S, -50.0, 1768.0),
+            (ThermocoupleType::T, -270.0, 400.0),
+        ];
+
+        for (thermocouple_type, t_lo, t_hi) in cases {
+            let tables = thermocouple_type.tables();
+            let steps = 50;
+            for i in 0..=steps {
+                let celsius = t_lo + (t_hi - t_lo) * (i as f32) / (steps as f32);
+                let mv = tables.forward_voltage(celsius).unwrap_or_else(|| {
+                    panic!("{thermocouple_type:?} at {celsius} degrees C had no forward voltage")
+                });
+                let back = tables.inverse_celsius(mv).unwrap_or_else(|| {
+                    panic!("{thermocouple_type:?} at {celsius} degrees C ({mv} mV) round-tripped out of range")
+                });
+                assert!(
+                    (back - celsius).abs() < 0.5,
+                    "{thermocouple_type:?} {celsius} degrees C round-tripped to {back} degrees C"
+                );
             }
         }
+    }
 
-        let first_u16 = (buffer[0] as u16) << 8 | (buffer[1] as u16);
-        let second_u16 = (buffer[2] as u16) << 8 | (buffer[3] as u16);
+    #[test]
+    fn decode_all_with_faults_reports_no_faults_when_the_fault_bits_are_clear() {
+        let (raw, faults) = decode_all_with_faults([0x00, 0x04, 0x00, 0x00]);
 
-        let thermocouple = bits_to_i16(first_u16.get_bits(THERMOCOUPLE_BITS), 14, 4, 2);
-        let internal = bits_to_i16(second_u16.get_bits(INTERNAL_BITS), 12, 16, 4);
+        assert_eq!(faults, Faults::default());
+        assert_eq!(raw.thermocouple, 1);
+        assert_eq!(raw.internal, 0);
+    }
 
-        Ok(FullResultRaw {
-            thermocouple,
-            internal,
-        })
+    /// A fault bit is decoded independently of the reading, so it must never be discarded
+    #[test]
+    fn decode_all_with_faults_decodes_each_fault_bit_independently_of_the_reading() {
+        let (raw, faults) = decode_all_with_faults([0x00, 0x04, 0x00, 0b0000_0111]);
+
+        assert!(faults.missing_thermocouple);
+        assert!(faults.ground_short);
+        assert!(faults.vcc_short);
+        assert_eq!(raw.thermocouple, 1);
     }
 
-    /// Reads both the thermocouple and the internal temperatures, converts them into degrees in the provided unit and resolves faults to one of vcc short, ground short or missing thermocouple
-    fn read_all(&mut self, chip_select: &mut CS, unit: Unit) -> Result<FullResult, Error<SPI, CS>> {
-        self.read_all_raw(chip_select).map(|r| r.convert(unit))
+    #[test]
+    fn raw_averager_discards_faulted_samples_and_averages_the_rest() {
+        let mut averager = RawAverager::default();
+        averager.push(
+            FullResultRaw {
+                thermocouple: 10,
+                internal: 100,
+            },
+            Faults::default(),
+        );
+        averager.push(
+            FullResultRaw {
+                thermocouple: 1000,
+                internal: 1000,
+            },
+            Faults {
+                vcc_short: true,
+                ..Faults::default()
+            },
+        );
+        averager.push(
+            FullResultRaw {
+                thermocouple: 20,
+                internal: 200,
+            },
+            Faults::default(),
+        );
+
+        let raw = averager.finish().expect("not every sample faulted");
+        assert_eq!(raw.thermocouple, 15);
+        assert_eq!(raw.internal, 150);
+    }
+
+    #[test]
+    fn raw_averager_returns_none_when_every_sample_faulted() {
+        let mut averager = RawAverager::default();
+        averager.push(
+            FullResultRaw {
+                thermocouple: 10,
+                internal: 100,
+            },
+            Faults {
+                ground_short: true,
+                ..Faults::default()
+            },
+        );
+        averager.push(
+            FullResultRaw {
+                thermocouple: 20,
+                internal: 200,
+            },
+            Faults {
+                missing_thermocouple: true,
+                ..Faults::default()
+            },
+        );
+
+        assert!(averager.finish().is_none());
     }
 }